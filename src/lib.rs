@@ -0,0 +1,350 @@
+use std::{fmt, fs::read_to_string, marker::PhantomData};
+
+mod parser;
+
+// Everything that can go wrong turning input into a `Hand`.
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    InvalidCard(char),
+    MissingBid,
+    BadBid(String),
+    WrongHandLength(usize),
+    Syntax(String),
+    Io(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidCard(c) => write!(f, "invalid card rank: {}", c),
+            ParseError::MissingBid => write!(f, "line is missing a bid column"),
+            ParseError::BadBid(s) => write!(f, "bid is not a valid number: {}", s),
+            ParseError::WrongHandLength(n) => write!(f, "hand has {} cards, expected 5", n),
+            ParseError::Syntax(e) => write!(f, "could not parse input: {}", e),
+            ParseError::Io(e) => write!(f, "could not read input file: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug)]
+pub enum HandType {
+    HighCard,
+    Pair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    fn to_ordinal(&self) -> i32 {
+        match self {
+            HandType::HighCard => 0,
+            HandType::Pair => 1,
+            HandType::TwoPair => 2,
+            HandType::ThreeOfAKind => 3,
+            HandType::FullHouse => 4,
+            HandType::FourOfAKind => 5,
+            HandType::FiveOfAKind => 6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum CardRank {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    JackOrJoker,
+    Queen,
+    King,
+    Ace,
+}
+
+impl TryFrom<char> for CardRank {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, ParseError> {
+        match c {
+            '2' => Ok(CardRank::Two),
+            '3' => Ok(CardRank::Three),
+            '4' => Ok(CardRank::Four),
+            '5' => Ok(CardRank::Five),
+            '6' => Ok(CardRank::Six),
+            '7' => Ok(CardRank::Seven),
+            '8' => Ok(CardRank::Eight),
+            '9' => Ok(CardRank::Nine),
+            'T' => Ok(CardRank::Ten),
+            'J' => Ok(CardRank::JackOrJoker),
+            'Q' => Ok(CardRank::Queen),
+            'K' => Ok(CardRank::King),
+            'A' => Ok(CardRank::Ace),
+            _ => Err(ParseError::InvalidCard(c)),
+        }
+    }
+}
+
+impl CardRank {
+    // Rank of the card with no wildcard adjustment; J always ranks as a Jack here.
+    // Rules that want J to behave differently override the value via `JokerRule::card_value`.
+    fn to_ordinal(self) -> i32 {
+        match self {
+            CardRank::Two => 1,
+            CardRank::Three => 2,
+            CardRank::Four => 3,
+            CardRank::Five => 4,
+            CardRank::Six => 5,
+            CardRank::Seven => 6,
+            CardRank::Eight => 7,
+            CardRank::Nine => 8,
+            CardRank::Ten => 9,
+            CardRank::JackOrJoker => 10,
+            CardRank::Queen => 11,
+            CardRank::King => 12,
+            CardRank::Ace => 13,
+        }
+    }
+
+    // Zero-based position of the card in a 13-bucket histogram, independent of
+    // any wildcard rule.
+    fn index(self) -> usize {
+        self.to_ordinal() as usize - 1
+    }
+}
+
+// A pluggable wildcard policy. Implementors decide what a "J" is worth when
+// comparing card strength, and how a hand's rank histogram should be folded
+// before classification. `Standard` and `Wild` below cover part 1 and part 2;
+// a future rule (e.g. wild "2"s) just needs a new zero-sized type.
+pub trait JokerRule: fmt::Debug {
+    fn card_value(rank: &CardRank) -> i32;
+    fn adjust_histogram(counts: &mut [u8; 13]);
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Standard;
+
+impl JokerRule for Standard {
+    fn card_value(rank: &CardRank) -> i32 {
+        rank.to_ordinal()
+    }
+
+    fn adjust_histogram(_counts: &mut [u8; 13]) {
+        // J is just a Jack; no folding needed.
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Wild;
+
+impl JokerRule for Wild {
+    fn card_value(rank: &CardRank) -> i32 {
+        match rank {
+            CardRank::JackOrJoker => 0,
+            rank => rank.to_ordinal(),
+        }
+    }
+
+    fn adjust_histogram(counts: &mut [u8; 13]) {
+        let joker_index = CardRank::JackOrJoker.index();
+        let joker_count = counts[joker_index];
+        if joker_count == 0 {
+            return;
+        }
+        counts[joker_index] = 0;
+        match counts.iter().enumerate().max_by_key(|(_, count)| **count) {
+            Some((max_index, &max_count)) if max_count > 0 => counts[max_index] += joker_count,
+            // All five cards were jokers; treat as five of a kind.
+            _ => counts[joker_index] = joker_count,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Hand<R: JokerRule> {
+    bid: i32,
+    // Hand type (high bits) followed by each card's rule-adjusted value (4
+    // bits apiece), computed once so sorting never has to re-derive it.
+    strength_key: u32,
+    rule: PhantomData<R>,
+}
+
+impl<R: JokerRule> Hand<R> {
+    fn new(cards: Vec<char>, bid: i32) -> Result<Hand<R>, ParseError> {
+        if cards.len() != 5 {
+            return Err(ParseError::WrongHandLength(cards.len()));
+        }
+        let cards = cards.into_iter().map(CardRank::try_from).collect::<Result<Vec<CardRank>, ParseError>>()?;
+        let hand_type = Self::classify(&cards);
+        let strength_key = Self::strength_key(&cards, &hand_type);
+        Ok(Hand { bid, strength_key, rule: PhantomData })
+    }
+
+    // Builds a histogram of rank counts, lets the joker rule fold it, then
+    // reads off the hand type from the resulting shape. Building the histogram
+    // does not depend on hand size, but only five-card shapes are matched; any
+    // other shape reads as HighCard.
+    fn classify(cards: &[CardRank]) -> HandType {
+        let mut histogram = [0u8; 13];
+        for card in cards {
+            histogram[card.index()] += 1;
+        }
+        R::adjust_histogram(&mut histogram);
+
+        let mut counts: Vec<u8> = histogram.into_iter().filter(|&count| count > 0).collect();
+        counts.sort_by(|a, b| b.cmp(a));
+
+        match counts.as_slice() {
+            [5] => HandType::FiveOfAKind,
+            [4, 1] => HandType::FourOfAKind,
+            [3, 2] => HandType::FullHouse,
+            [3, 1, 1] => HandType::ThreeOfAKind,
+            [2, 2, 1] => HandType::TwoPair,
+            [2, 1, 1, 1] => HandType::Pair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    // Packs the hand type and all five card values into one u32: type << 20 |
+    // c0 << 16 | c1 << 12 | ... | c4. Comparing hands then reduces to
+    // comparing this integer, with no re-parsing and no tie-break fallback.
+    fn strength_key(cards: &[CardRank], hand_type: &HandType) -> u32 {
+        cards.iter().enumerate().fold((hand_type.to_ordinal() as u32) << 20, |key, (i, card)| {
+            key | (R::card_value(card) as u32) << (16 - 4 * i)
+        })
+    }
+}
+
+// Turns a nom grammar failure into a `ParseError`. `Space`/`Digit` are the
+// `ErrorKind`s nom reports when `space1`/the bid's `u32` parser can't find
+// what they're looking for, which maps directly onto our own missing-bid and
+// bad-bid cases; anything else becomes a generic syntax error with the line
+// number where parsing gave up.
+fn describe_parse_failure(input: &str, err: nom::Err<nom::error::Error<&str>>) -> ParseError {
+    let (remaining, kind) = match &err {
+        nom::Err::Error(e) | nom::Err::Failure(e) => (e.input, e.code),
+        nom::Err::Incomplete(_) => return ParseError::Syntax("unexpected end of input".to_string()),
+    };
+
+    // `separated_list1` treats a line it can't parse as "end of list" rather
+    // than a hard failure, so for any line past the first, `all_consuming`
+    // only ever reports the leftover as a generic `Eof`. In that case (and
+    // only that case — for a first-line failure `kind` is already precise),
+    // re-run the line grammar directly on the leftover to recover why it
+    // really failed.
+    let (remaining, kind) = if kind == nom::error::ErrorKind::Eof {
+        match parser::line(remaining) {
+            Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => (e.input, e.code),
+            _ => (remaining, kind),
+        }
+    } else {
+        (remaining, kind)
+    };
+
+    match kind {
+        nom::error::ErrorKind::Space | nom::error::ErrorKind::MultiSpace => ParseError::MissingBid,
+        nom::error::ErrorKind::Digit => ParseError::BadBid(remaining.lines().next().unwrap_or(remaining).to_string()),
+        _ => {
+            let line = input[..input.len() - remaining.len()].matches('\n').count() + 1;
+            ParseError::Syntax(format!("line {}: unexpected input near {:?}", line, remaining.lines().next().unwrap_or(remaining)))
+        }
+    }
+}
+
+// Parses the whole input with the `nom` grammar in `parser`, then turns each
+// raw (cards, bid) pair into a validated `Hand`.
+fn get_hands_from_str<R: JokerRule>(input: &str) -> Result<Vec<Hand<R>>, ParseError> {
+    let (_, rows) = parser::lines(input).map_err(|e| describe_parse_failure(input, e))?;
+    rows.into_iter().map(|(cards, bid)| Hand::new(cards.chars().collect(), bid as i32)).collect()
+}
+
+fn get_hands<R: JokerRule>(filename: &str) -> Result<Vec<Hand<R>>, ParseError> {
+    let content = read_to_string(filename).map_err(|e| ParseError::Io(e.to_string()))?;
+    get_hands_from_str(&content)
+}
+
+fn sort_hands<R: JokerRule>(hands: &mut [Hand<R>]) {
+    hands.sort_by_key(|hand| hand.strength_key);
+}
+
+fn total_winnings<R: JokerRule>(mut hands: Vec<Hand<R>>) -> usize {
+    sort_hands(&mut hands);
+    let mut sum: usize = 0;
+    for (i, hand) in hands.iter().enumerate() {
+        let winnings = hand.bid as usize * (i + 1);
+        sum += winnings;
+    }
+    sum
+}
+
+pub fn solution<R: JokerRule>(filename: &str) -> Result<usize, ParseError> {
+    let hands = get_hands::<R>(filename)?;
+    Ok(total_winnings(hands))
+}
+
+pub fn solution_from_str<R: JokerRule>(input: &str) -> Result<usize, ParseError> {
+    let hands = get_hands_from_str::<R>(input)?;
+    Ok(total_winnings(hands))
+}
+
+pub fn part_1(filename: &str) -> Result<usize, ParseError> {
+    solution::<Standard>(filename)
+}
+
+pub fn part_2(filename: &str) -> Result<usize, ParseError> {
+    solution::<Wild>(filename)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483\n";
+
+    #[test]
+    fn standard_rule_scores_the_example() {
+        assert_eq!(solution_from_str::<Standard>(EXAMPLE), Ok(6440));
+    }
+
+    #[test]
+    fn wild_rule_scores_the_example() {
+        assert_eq!(solution_from_str::<Wild>(EXAMPLE), Ok(5905));
+    }
+
+    #[test]
+    fn missing_bid_column_is_reported() {
+        assert!(matches!(solution_from_str::<Standard>("32T3K\n"), Err(ParseError::MissingBid)));
+    }
+
+    #[test]
+    fn non_numeric_bid_is_reported() {
+        assert!(matches!(solution_from_str::<Standard>("32T3K abc\n"), Err(ParseError::BadBid(_))));
+    }
+
+    #[test]
+    fn wrong_length_hand_is_reported() {
+        assert!(matches!(solution_from_str::<Standard>("32T3K9 765\n"), Err(ParseError::WrongHandLength(6))));
+    }
+
+    #[test]
+    fn bad_bid_on_a_later_line_is_still_reported_precisely() {
+        assert!(matches!(
+            solution_from_str::<Standard>("32T3K 765\nT55J5 abc\n"),
+            Err(ParseError::BadBid(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_card_rank_is_reported() {
+        assert!(matches!(solution_from_str::<Standard>("XX333 765\n"), Err(ParseError::InvalidCard('X'))));
+    }
+}
@@ -0,0 +1,29 @@
+use nom::{
+    bytes::complete::take_while1,
+    character::complete::{line_ending, space1, u32 as bid},
+    combinator::{all_consuming, opt},
+    multi::separated_list1,
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+
+// A hand token, e.g. "32T3K". Deliberately variable-length: whether it's
+// actually five cards of valid ranks is `Hand::new`'s job, so a too-short or
+// too-long token still parses here and surfaces as `ParseError::WrongHandLength`
+// (or `InvalidCard`) instead of an opaque grammar failure.
+fn hand(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| c.is_ascii_alphanumeric())(input)
+}
+
+// One "<hand> <bid>" line. `pub(crate)` so callers can re-parse a leftover
+// line directly when `lines` below has already given up on it — see
+// `describe_parse_failure` in lib.rs.
+pub(crate) fn line(input: &str) -> IResult<&str, (&str, u32)> {
+    separated_pair(hand, space1, bid)(input)
+}
+
+// The whole file: one line per hand, with no trailing garbage left over once
+// the last line (and an optional trailing newline) is consumed.
+pub(crate) fn lines(input: &str) -> IResult<&str, Vec<(&str, u32)>> {
+    all_consuming(terminated(separated_list1(line_ending, line), opt(line_ending)))(input)
+}